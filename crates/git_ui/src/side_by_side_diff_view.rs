@@ -2,7 +2,7 @@
 
 use anyhow::Result;
 use buffer_diff::{BufferDiff, BufferDiffSnapshot};
-use editor::{Editor, EditorEvent, MultiBuffer};
+use editor::{Autoscroll, Direction, Editor, EditorEvent, MultiBuffer};
 use futures::{FutureExt, select_biased};
 use gpui::{
     AnyElement, AnyView, App, AppContext as _, AsyncApp, Context, Entity, EventEmitter,
@@ -12,6 +12,7 @@ use language::Buffer;
 use project::Project;
 use std::{
     any::{Any, TypeId},
+    ops::Range,
     pin::pin,
     sync::Arc,
     time::Duration,
@@ -31,19 +32,251 @@ pub struct SideBySideDiffView {
     old_buffer: Entity<Buffer>,
     new_buffer: Entity<Buffer>,
     diff: Entity<BufferDiff>,
+    /// Present only in three-way (merge conflict) mode: the "theirs" pane,
+    /// diffed against the same base/ancestor buffer (`old_buffer`) that
+    /// `left_editor` shows, not against `right_editor`'s "ours" buffer.
+    theirs: Option<TheirsPane>,
     buffer_changes_tx: watch::Sender<()>,
     _recalculate_diff_task: Task<Result<()>>,
     focused_pane: FocusedPane,
+    /// Piecewise-linear map between old-buffer rows and new-buffer rows,
+    /// rebuilt every time the diff snapshot changes.
+    row_map: RowMap,
+    /// Set while a scroll is being applied programmatically to the follower
+    /// pane, so that it doesn't bounce back and re-sync the pane that
+    /// initiated the scroll.
+    syncing_scroll: bool,
+}
+
+/// The third pane of a three-way merge view: the ancestor/base buffer
+/// compared against "theirs", kept alongside its own row map so scrolling
+/// stays diff-aligned independently of the base↔ours pair.
+struct TheirsPane {
+    editor: Entity<Editor>,
+    buffer: Entity<Buffer>,
+    diff: Entity<BufferDiff>,
+    row_map: RowMap,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum FocusedPane {
     Left,
     Right,
+    Theirs,
 }
 
 const RECALCULATE_DIFF_DEBOUNCE: Duration = Duration::from_millis(250);
 
+gpui::actions!(
+    side_by_side_diff_view,
+    [GoToNextHunk, GoToPrevHunk, ApplyHunkFromLeft, RevertHunk]
+);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum HunkApplyDirection {
+    /// Replace the new buffer's hunk range with the old buffer's content.
+    LeftToRight,
+    /// Replace the old buffer's hunk range with the new buffer's content.
+    RightToLeft,
+}
+
+/// Highlight type tag used to register and clear the intra-line word diff
+/// highlights independently of any other highlights the editors might have.
+enum IntraHunkHighlight {}
+
+/// Splits a line into runs of word characters (Unicode alphanumerics and `_`)
+/// and runs of non-word characters, returning the byte range of each run in
+/// order.
+fn tokenize_line(line: &str) -> Vec<Range<usize>> {
+    let mut tokens = Vec::new();
+    let mut chars = line.char_indices().peekable();
+    while let Some(&(start, ch)) = chars.peek() {
+        let is_word = ch.is_alphanumeric() || ch == '_';
+        let mut end = start + ch.len_utf8();
+        chars.next();
+        while let Some(&(idx, next_ch)) = chars.peek() {
+            if (next_ch.is_alphanumeric() || next_ch == '_') != is_word {
+                break;
+            }
+            end = idx + next_ch.len_utf8();
+            chars.next();
+        }
+        tokens.push(start..end);
+    }
+    tokens
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TokenDiffOp {
+    Delete,
+    Insert,
+}
+
+/// A standard LCS word diff: longest-common-subsequence over the token
+/// slices, backtracked into one `(op, index)` entry per deleted old token or
+/// inserted new token. Equal tokens produce no entry, and adjacent changed
+/// tokens are not merged into spans.
+fn diff_tokens(old_tokens: &[&str], new_tokens: &[&str]) -> Vec<(TokenDiffOp, usize)> {
+    let n = old_tokens.len();
+    let m = new_tokens.len();
+    let mut dp = vec![vec![0u32; m + 1]; n + 1];
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            dp[i][j] = if old_tokens[i] == new_tokens[j] {
+                dp[i + 1][j + 1] + 1
+            } else {
+                dp[i + 1][j].max(dp[i][j + 1])
+            };
+        }
+    }
+
+    let mut ops = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < n && j < m {
+        if old_tokens[i] == new_tokens[j] {
+            i += 1;
+            j += 1;
+        } else if dp[i + 1][j] >= dp[i][j + 1] {
+            ops.push((TokenDiffOp::Delete, i));
+            i += 1;
+        } else {
+            ops.push((TokenDiffOp::Insert, j));
+            j += 1;
+        }
+    }
+    while i < n {
+        ops.push((TokenDiffOp::Delete, i));
+        i += 1;
+    }
+    while j < m {
+        ops.push((TokenDiffOp::Insert, j));
+        j += 1;
+    }
+    ops
+}
+
+/// A single piece of the piecewise-linear row map: an old-buffer row range
+/// and the new-buffer row range it corresponds to. Rows inside the range are
+/// interpolated proportionally; unchanged regions have matching lengths on
+/// both sides, so interpolation there degenerates to a constant offset.
+#[derive(Debug, Clone, Copy)]
+struct RowMapSegment {
+    old_start: f32,
+    old_end: f32,
+    new_start: f32,
+    new_end: f32,
+}
+
+#[derive(Debug, Clone, Default)]
+struct RowMap {
+    segments: Vec<RowMapSegment>,
+}
+
+impl RowMap {
+    fn from_diff(
+        diff_snapshot: &BufferDiffSnapshot,
+        old_snapshot: &text::BufferSnapshot,
+        new_snapshot: &text::BufferSnapshot,
+    ) -> Self {
+        let mut segments = Vec::new();
+        let mut old_cursor = 0u32;
+        let mut new_cursor = 0u32;
+
+        for hunk in diff_snapshot.hunks_in_row_range(0..u32::MAX, new_snapshot) {
+            let old_start = old_snapshot
+                .offset_to_point(hunk.diff_base_byte_range.start)
+                .row;
+            let old_end = old_snapshot
+                .offset_to_point(hunk.diff_base_byte_range.end)
+                .row;
+            let new_start = hunk.row_range.start;
+            let new_end = hunk.row_range.end;
+
+            if new_start > new_cursor {
+                let unchanged_len = new_start - new_cursor;
+                segments.push(RowMapSegment {
+                    old_start: old_cursor as f32,
+                    old_end: (old_cursor + unchanged_len) as f32,
+                    new_start: new_cursor as f32,
+                    new_end: new_start as f32,
+                });
+                old_cursor += unchanged_len;
+            }
+
+            segments.push(RowMapSegment {
+                old_start: old_start as f32,
+                old_end: old_end.max(old_start + 1) as f32,
+                new_start: new_start as f32,
+                new_end: new_end.max(new_start + 1) as f32,
+            });
+
+            old_cursor = old_end;
+            new_cursor = new_end;
+        }
+
+        let max_new_row = new_snapshot.max_point().row;
+        if max_new_row > new_cursor {
+            let unchanged_len = max_new_row - new_cursor;
+            segments.push(RowMapSegment {
+                old_start: old_cursor as f32,
+                old_end: (old_cursor + unchanged_len) as f32,
+                new_start: new_cursor as f32,
+                new_end: max_new_row as f32,
+            });
+        }
+
+        Self { segments }
+    }
+
+    fn old_to_new(&self, row: f32) -> f32 {
+        self.map(row, true)
+    }
+
+    fn new_to_old(&self, row: f32) -> f32 {
+        self.map(row, false)
+    }
+
+    fn map(&self, row: f32, from_old: bool) -> f32 {
+        let Some(last) = self.segments.last() else {
+            return row;
+        };
+
+        for segment in &self.segments {
+            let (from_start, from_end, to_start, to_end) = if from_old {
+                (
+                    segment.old_start,
+                    segment.old_end,
+                    segment.new_start,
+                    segment.new_end,
+                )
+            } else {
+                (
+                    segment.new_start,
+                    segment.new_end,
+                    segment.old_start,
+                    segment.old_end,
+                )
+            };
+
+            if row >= from_start && row <= from_end {
+                let from_len = (from_end - from_start).max(1.);
+                let fraction = (row - from_start) / from_len;
+                return to_start + fraction * (to_end - to_start);
+            }
+        }
+
+        // Past the end of every segment (e.g. blank trailing rows): keep the
+        // same offset from the last known point rather than clamping, so
+        // scrolling to the bottom of a longer pane still tracks.
+        let (from_end, to_end) = if from_old {
+            (last.old_end, last.new_end)
+        } else {
+            (last.new_end, last.old_end)
+        };
+        to_end + (row - from_end)
+    }
+}
+
 impl SideBySideDiffView {
     pub fn open(
         old_buffer: Entity<Buffer>,
@@ -63,6 +296,48 @@ impl SideBySideDiffView {
                         old_buffer,
                         new_buffer,
                         buffer_diff,
+                        None,
+                        project.clone(),
+                        window,
+                        cx,
+                    )
+                });
+
+                let pane = workspace.active_pane();
+                pane.update(cx, |pane, cx| {
+                    pane.add_item(Box::new(diff_view.clone()), true, true, None, window, cx);
+                });
+
+                diff_view
+            })
+        })
+    }
+
+    /// Open a three-pane merge-conflict view: `base_buffer` is the common
+    /// ancestor, `ours_buffer` is the side that will be saved, and
+    /// `theirs_buffer` is shown alongside it for reference. Builds the base↔ours and base↔theirs
+    /// diffs up front, the same way `open` builds its single diff.
+    pub fn open_three_way(
+        base_buffer: Entity<Buffer>,
+        ours_buffer: Entity<Buffer>,
+        theirs_buffer: Entity<Buffer>,
+        workspace: &Workspace,
+        window: &mut Window,
+        cx: &mut App,
+    ) -> Task<Result<Entity<Self>>> {
+        let workspace = workspace.weak_handle();
+        window.spawn(cx, async move |cx| {
+            let project = workspace.update(cx, |workspace, _| workspace.project().clone())?;
+            let ours_diff = build_buffer_diff(&base_buffer, &ours_buffer, cx).await?;
+            let theirs_diff = build_buffer_diff(&base_buffer, &theirs_buffer, cx).await?;
+
+            workspace.update_in(cx, |workspace, window, cx| {
+                let diff_view = cx.new(|cx| {
+                    SideBySideDiffView::new(
+                        base_buffer,
+                        ours_buffer,
+                        ours_diff,
+                        Some((theirs_buffer, theirs_diff)),
                         project.clone(),
                         window,
                         cx,
@@ -83,6 +358,7 @@ impl SideBySideDiffView {
         old_buffer: Entity<Buffer>,
         new_buffer: Entity<Buffer>,
         diff: Entity<BufferDiff>,
+        theirs: Option<(Entity<Buffer>, Entity<BufferDiff>)>,
         project: Entity<Project>,
         window: &mut Window,
         cx: &mut Context<Self>,
@@ -133,12 +409,66 @@ impl SideBySideDiffView {
             editor
         });
 
-        // TODO: Implement synchronized scrolling
-        // For now, we'll skip scroll synchronization to get basic functionality working
+        let row_map = RowMap::from_diff(
+            &diff.read(cx).snapshot(cx),
+            &old_buffer.read(cx).snapshot(),
+            &new_buffer.read(cx).snapshot(),
+        );
+
+        cx.subscribe(&left_editor, Self::handle_left_editor_event)
+            .detach();
+        cx.subscribe(&right_editor, Self::handle_right_editor_event)
+            .detach();
+
+        // Create the theirs editor for three-way mode, diffed against the
+        // same base buffer as the left (ancestor) pane.
+        let theirs_pane = theirs.map(|(theirs_buffer, theirs_diff)| {
+            let theirs_multibuffer = cx.new(|cx| {
+                let mut multibuffer = MultiBuffer::singleton(theirs_buffer.clone(), cx);
+                multibuffer.add_diff(theirs_diff.clone(), cx);
+                multibuffer
+            });
+            let theirs_editor = cx.new(|cx| {
+                let mut editor = Editor::for_multibuffer(
+                    theirs_multibuffer.clone(),
+                    Some(project.clone()),
+                    window,
+                    cx,
+                );
+                editor.start_temporary_diff_override();
+                editor.disable_diagnostics(cx);
+                editor.set_expand_all_diff_hunks(cx);
+                editor.set_render_diff_hunk_controls(
+                    Arc::new(|_, _, _, _, _, _, _, _| gpui::Empty.into_any_element()),
+                    cx,
+                );
+                editor
+            });
+
+            cx.subscribe(&theirs_editor, Self::handle_theirs_editor_event)
+                .detach();
+
+            let row_map = RowMap::from_diff(
+                &theirs_diff.read(cx).snapshot(cx),
+                &old_buffer.read(cx).snapshot(),
+                &theirs_buffer.read(cx).snapshot(),
+            );
+
+            TheirsPane {
+                editor: theirs_editor,
+                buffer: theirs_buffer,
+                diff: theirs_diff,
+                row_map,
+            }
+        });
 
         let (buffer_changes_tx, mut buffer_changes_rx) = watch::channel(());
 
-        for buffer in [&old_buffer, &new_buffer] {
+        let mut watched_buffers = vec![old_buffer.clone(), new_buffer.clone()];
+        if let Some(theirs_pane) = &theirs_pane {
+            watched_buffers.push(theirs_pane.buffer.clone());
+        }
+        for buffer in &watched_buffers {
             cx.subscribe(buffer, move |this, _, event, _| match event {
                 language::BufferEvent::Edited
                 | language::BufferEvent::LanguageChanged
@@ -150,14 +480,20 @@ impl SideBySideDiffView {
             .detach();
         }
 
-        Self {
+        let theirs_diff_for_task = theirs_pane.as_ref().map(|p| p.diff.clone());
+        let theirs_buffer_for_task = theirs_pane.as_ref().map(|p| p.buffer.clone());
+
+        let mut this = Self {
             left_editor,
             right_editor,
             old_buffer,
             new_buffer,
             diff: diff.clone(),
+            theirs: theirs_pane,
             buffer_changes_tx,
             focused_pane: FocusedPane::Left,
+            row_map,
+            syncing_scroll: false,
             _recalculate_diff_task: cx.spawn(async move |this, cx| {
                 while buffer_changes_rx.recv().await.is_ok() {
                     loop {
@@ -184,7 +520,7 @@ impl SideBySideDiffView {
                             BufferDiffSnapshot::new_with_base_buffer(
                                 new_snapshot.text.clone(),
                                 Some(old_snapshot.text().into()),
-                                old_snapshot,
+                                old_snapshot.clone(),
                                 cx,
                             )
                         })?
@@ -192,11 +528,50 @@ impl SideBySideDiffView {
                     diff.update(cx, |diff, cx| {
                         diff.set_snapshot(diff_snapshot, &new_snapshot, cx)
                     })?;
+
+                    if let (Some(theirs_diff), Some(theirs_buffer)) =
+                        (&theirs_diff_for_task, &theirs_buffer_for_task)
+                    {
+                        let theirs_snapshot =
+                            theirs_buffer.read_with(cx, |buffer, _| buffer.snapshot())?;
+                        let theirs_diff_snapshot = cx
+                            .update(|cx| {
+                                BufferDiffSnapshot::new_with_base_buffer(
+                                    theirs_snapshot.text.clone(),
+                                    Some(old_snapshot.text().into()),
+                                    old_snapshot,
+                                    cx,
+                                )
+                            })?
+                            .await;
+                        theirs_diff.update(cx, |diff, cx| {
+                            diff.set_snapshot(theirs_diff_snapshot, &theirs_snapshot, cx)
+                        })?;
+                    }
+
+                    this.update(cx, |this, cx| {
+                        this.row_map = RowMap::from_diff(
+                            &this.diff.read(cx).snapshot(cx),
+                            &this.old_buffer.read(cx).snapshot(),
+                            &new_snapshot,
+                        );
+                        if let Some(theirs_pane) = &mut this.theirs {
+                            theirs_pane.row_map = RowMap::from_diff(
+                                &theirs_pane.diff.read(cx).snapshot(cx),
+                                &this.old_buffer.read(cx).snapshot(),
+                                &theirs_pane.buffer.read(cx).snapshot(),
+                            );
+                        }
+                        this.update_intra_hunk_highlights(cx);
+                    })?;
                     log::trace!("finish recalculating side-by-side diff");
                 }
                 Ok(())
             }),
-        }
+        };
+
+        this.update_intra_hunk_highlights(cx);
+        this
     }
 
     pub fn switch_to_left_pane(&mut self, window: &mut Window, cx: &mut Context<Self>) {
@@ -209,14 +584,486 @@ impl SideBySideDiffView {
         window.focus(&self.right_editor.focus_handle(cx));
     }
 
+    pub fn switch_to_theirs_pane(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let Some(theirs) = &self.theirs else { return };
+        self.focused_pane = FocusedPane::Theirs;
+        window.focus(&theirs.editor.focus_handle(cx));
+    }
+
+    /// Cycle through whichever panes are present: left → right → theirs (if
+    /// a three-way merge is open) → left.
     pub fn toggle_pane(&mut self, window: &mut Window, cx: &mut Context<Self>) {
         match self.focused_pane {
             FocusedPane::Left => self.switch_to_right_pane(window, cx),
-            FocusedPane::Right => self.switch_to_left_pane(window, cx),
+            FocusedPane::Right if self.theirs.is_some() => self.switch_to_theirs_pane(window, cx),
+            FocusedPane::Right | FocusedPane::Theirs => self.switch_to_left_pane(window, cx),
+        }
+    }
+
+    fn handle_left_editor_event(
+        this: &mut Self,
+        _: Entity<Editor>,
+        event: &EditorEvent,
+        cx: &mut Context<Self>,
+    ) {
+        if let EditorEvent::ScrollPositionChanged { .. } = event {
+            this.sync_scroll(FocusedPane::Left, cx);
+        }
+    }
+
+    fn handle_right_editor_event(
+        this: &mut Self,
+        _: Entity<Editor>,
+        event: &EditorEvent,
+        cx: &mut Context<Self>,
+    ) {
+        if let EditorEvent::ScrollPositionChanged { .. } = event {
+            this.sync_scroll(FocusedPane::Right, cx);
+        }
+    }
+
+    fn handle_theirs_editor_event(
+        this: &mut Self,
+        _: Entity<Editor>,
+        event: &EditorEvent,
+        cx: &mut Context<Self>,
+    ) {
+        if let EditorEvent::ScrollPositionChanged { .. } = event {
+            this.sync_scroll(FocusedPane::Theirs, cx);
+        }
+    }
+
+    /// Mirror a scroll that originated in `source` onto every other present
+    /// pane, mapping rows through the relevant row map so all panes stay
+    /// diff-aligned regardless of which one the user scrolled. The base
+    /// (left) pane is the hub both ours and theirs map through; ours and
+    /// theirs never sync directly against each other.
+    fn sync_scroll(&mut self, source: FocusedPane, cx: &mut Context<Self>) {
+        if self.syncing_scroll {
+            return;
+        }
+
+        self.syncing_scroll = true;
+        match source {
+            FocusedPane::Left => {
+                let scroll_position = self.left_editor.read(cx).scroll_position(cx);
+                let new_row = self.row_map.old_to_new(scroll_position.y);
+                self.right_editor.update(cx, |editor, cx| {
+                    editor.set_scroll_position(gpui::point(scroll_position.x, new_row), cx);
+                });
+                if let Some(theirs) = &self.theirs {
+                    let theirs_row = theirs.row_map.old_to_new(scroll_position.y);
+                    theirs.editor.update(cx, |editor, cx| {
+                        editor.set_scroll_position(gpui::point(scroll_position.x, theirs_row), cx);
+                    });
+                }
+            }
+            FocusedPane::Right => {
+                let scroll_position = self.right_editor.read(cx).scroll_position(cx);
+                let old_row = self.row_map.new_to_old(scroll_position.y);
+                self.left_editor.update(cx, |editor, cx| {
+                    editor.set_scroll_position(gpui::point(scroll_position.x, old_row), cx);
+                });
+                if let Some(theirs) = &self.theirs {
+                    let theirs_row = theirs.row_map.old_to_new(old_row);
+                    theirs.editor.update(cx, |editor, cx| {
+                        editor.set_scroll_position(gpui::point(scroll_position.x, theirs_row), cx);
+                    });
+                }
+            }
+            FocusedPane::Theirs => {
+                let Some(theirs) = &self.theirs else {
+                    self.syncing_scroll = false;
+                    return;
+                };
+                let scroll_position = theirs.editor.read(cx).scroll_position(cx);
+                let old_row = theirs.row_map.new_to_old(scroll_position.y);
+                self.left_editor.update(cx, |editor, cx| {
+                    editor.set_scroll_position(gpui::point(scroll_position.x, old_row), cx);
+                });
+                let new_row = self.row_map.old_to_new(old_row);
+                self.right_editor.update(cx, |editor, cx| {
+                    editor.set_scroll_position(gpui::point(scroll_position.x, new_row), cx);
+                });
+            }
+        }
+        self.syncing_scroll = false;
+    }
+
+    pub fn go_to_next_hunk(
+        &mut self,
+        _: &GoToNextHunk,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.go_to_hunk(Direction::Next, window, cx);
+    }
+
+    pub fn go_to_prev_hunk(
+        &mut self,
+        _: &GoToPrevHunk,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.go_to_hunk(Direction::Prev, window, cx);
+    }
+
+    /// Move both editors' selections to the next/previous changed hunk,
+    /// relative to whichever pane currently has the cursor, wrapping around
+    /// from the last hunk to the first (and vice versa).
+    fn go_to_hunk(&mut self, direction: Direction, window: &mut Window, cx: &mut Context<Self>) {
+        // In three-way mode, navigating while the theirs pane is focused
+        // walks the base↔theirs hunks and only moves the base/theirs
+        // selections; base↔ours navigation is unaffected.
+        if self.focused_pane == FocusedPane::Theirs {
+            self.go_to_hunk_theirs(direction, window, cx);
+            return;
+        }
+
+        let new_snapshot = self.new_buffer.read(cx).snapshot();
+        let old_snapshot = self.old_buffer.read(cx).snapshot();
+        let diff_snapshot = self.diff.read(cx).snapshot(cx);
+        let hunks: Vec<_> = diff_snapshot
+            .hunks_in_row_range(0..u32::MAX, &new_snapshot)
+            .collect();
+        let Some(hunk) = (match self.focused_pane {
+            FocusedPane::Left => {
+                let old_row = self
+                    .left_editor
+                    .read(cx)
+                    .selections
+                    .newest::<Point>(cx)
+                    .head()
+                    .row;
+                let new_row = self.row_map.old_to_new(old_row as f32) as u32;
+                Self::hunk_in_direction(&hunks, new_row, direction)
+            }
+            FocusedPane::Right => {
+                let new_row = self
+                    .right_editor
+                    .read(cx)
+                    .selections
+                    .newest::<Point>(cx)
+                    .head()
+                    .row;
+                Self::hunk_in_direction(&hunks, new_row, direction)
+            }
+            FocusedPane::Theirs => unreachable!("handled above"),
+        }) else {
+            return;
+        };
+
+        let new_anchor = hunk.buffer_range.start;
+        let old_anchor = old_snapshot.anchor_before(hunk.diff_base_byte_range.start);
+
+        self.left_editor.update(cx, |editor, cx| {
+            editor.change_selections(Default::default(), window, cx, |selections| {
+                selections.select_anchor_ranges([old_anchor..old_anchor]);
+            });
+            editor.request_autoscroll(Autoscroll::center(), cx);
+        });
+        self.right_editor.update(cx, |editor, cx| {
+            editor.change_selections(Default::default(), window, cx, |selections| {
+                selections.select_anchor_ranges([new_anchor..new_anchor]);
+            });
+            editor.request_autoscroll(Autoscroll::center(), cx);
+        });
+
+        cx.emit(EditorEvent::SelectionsChanged { local: true });
+    }
+
+    fn go_to_hunk_theirs(
+        &mut self,
+        direction: Direction,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let Some(theirs) = &self.theirs else { return };
+        let theirs_snapshot = theirs.buffer.read(cx).snapshot();
+        let old_snapshot = self.old_buffer.read(cx).snapshot();
+        let diff_snapshot = theirs.diff.read(cx).snapshot(cx);
+        let hunks: Vec<_> = diff_snapshot
+            .hunks_in_row_range(0..u32::MAX, &theirs_snapshot)
+            .collect();
+
+        let theirs_row = theirs
+            .editor
+            .read(cx)
+            .selections
+            .newest::<Point>(cx)
+            .head()
+            .row;
+        let Some(hunk) = Self::hunk_in_direction(&hunks, theirs_row, direction) else {
+            return;
+        };
+
+        let theirs_anchor = hunk.buffer_range.start;
+        let old_anchor = old_snapshot.anchor_before(hunk.diff_base_byte_range.start);
+
+        self.left_editor.update(cx, |editor, cx| {
+            editor.change_selections(Default::default(), window, cx, |selections| {
+                selections.select_anchor_ranges([old_anchor..old_anchor]);
+            });
+            editor.request_autoscroll(Autoscroll::center(), cx);
+        });
+        theirs.editor.update(cx, |editor, cx| {
+            editor.change_selections(Default::default(), window, cx, |selections| {
+                selections.select_anchor_ranges([theirs_anchor..theirs_anchor]);
+            });
+            editor.request_autoscroll(Autoscroll::center(), cx);
+        });
+
+        cx.emit(EditorEvent::SelectionsChanged { local: true });
+    }
+
+    /// Recompute word-level highlights for every modified line in every
+    /// hunk: lines are paired up by their relative index within the hunk,
+    /// diffed token-by-token, and the changed spans are registered as text
+    /// highlights. Lines left unpaired because the hunk's old/new line
+    /// counts differ fall back to whole-line highlighting.
+    fn update_intra_hunk_highlights(&mut self, cx: &mut Context<Self>) {
+        let old_snapshot = self.old_buffer.read(cx).snapshot();
+        let new_snapshot = self.new_buffer.read(cx).snapshot();
+        let diff_snapshot = self.diff.read(cx).snapshot(cx);
+
+        let mut old_ranges: Vec<Range<Anchor>> = Vec::new();
+        let mut new_ranges: Vec<Range<Anchor>> = Vec::new();
+
+        let whole_line_range =
+            |snapshot: &text::BufferSnapshot, row: u32| -> Range<Anchor> {
+                let start = snapshot.point_to_offset(Point::new(row, 0));
+                let end = start + snapshot.line_len(row) as usize;
+                snapshot.anchor_before(start)..snapshot.anchor_after(end)
+            };
+
+        for hunk in diff_snapshot.hunks_in_row_range(0..u32::MAX, &new_snapshot) {
+            let old_start_row = old_snapshot
+                .offset_to_point(hunk.diff_base_byte_range.start)
+                .row;
+            let old_end_row = old_snapshot
+                .offset_to_point(hunk.diff_base_byte_range.end)
+                .row;
+            let new_start_row = hunk.row_range.start;
+            let new_end_row = hunk.row_range.end;
+
+            let old_len = old_end_row.saturating_sub(old_start_row);
+            let new_len = new_end_row.saturating_sub(new_start_row);
+            let paired = old_len.min(new_len);
+
+            for i in 0..paired {
+                let old_row = old_start_row + i;
+                let new_row = new_start_row + i;
+                let old_line_start = old_snapshot.point_to_offset(Point::new(old_row, 0));
+                let new_line_start = new_snapshot.point_to_offset(Point::new(new_row, 0));
+                let old_line: String = old_snapshot
+                    .text_for_range(
+                        Point::new(old_row, 0)..Point::new(old_row, old_snapshot.line_len(old_row)),
+                    )
+                    .collect();
+                let new_line: String = new_snapshot
+                    .text_for_range(
+                        Point::new(new_row, 0)..Point::new(new_row, new_snapshot.line_len(new_row)),
+                    )
+                    .collect();
+
+                if old_line == new_line {
+                    continue;
+                }
+
+                let old_tokens = tokenize_line(&old_line);
+                let new_tokens = tokenize_line(&new_line);
+                let old_words: Vec<&str> =
+                    old_tokens.iter().map(|r| &old_line[r.clone()]).collect();
+                let new_words: Vec<&str> =
+                    new_tokens.iter().map(|r| &new_line[r.clone()]).collect();
+
+                for (op, index) in diff_tokens(&old_words, &new_words) {
+                    match op {
+                        TokenDiffOp::Delete => {
+                            let range = &old_tokens[index];
+                            let start = old_line_start + range.start;
+                            let end = old_line_start + range.end;
+                            old_ranges.push(
+                                old_snapshot.anchor_before(start)..old_snapshot.anchor_after(end),
+                            );
+                        }
+                        TokenDiffOp::Insert => {
+                            let range = &new_tokens[index];
+                            let start = new_line_start + range.start;
+                            let end = new_line_start + range.end;
+                            new_ranges.push(
+                                new_snapshot.anchor_before(start)..new_snapshot.anchor_after(end),
+                            );
+                        }
+                    }
+                }
+            }
+
+            for row in (old_start_row + paired)..old_end_row {
+                old_ranges.push(whole_line_range(&old_snapshot, row));
+            }
+            for row in (new_start_row + paired)..new_end_row {
+                new_ranges.push(whole_line_range(&new_snapshot, row));
+            }
+        }
+
+        self.left_editor.update(cx, |editor, cx| {
+            editor.highlight_text::<IntraHunkHighlight>(
+                old_ranges,
+                intra_hunk_highlight_style(false, cx),
+                cx,
+            );
+        });
+        self.right_editor.update(cx, |editor, cx| {
+            editor.highlight_text::<IntraHunkHighlight>(
+                new_ranges,
+                intra_hunk_highlight_style(true, cx),
+                cx,
+            );
+        });
+    }
+
+    fn hunk_in_direction<'a>(
+        hunks: &'a [buffer_diff::DiffHunk],
+        row: u32,
+        direction: Direction,
+    ) -> Option<&'a buffer_diff::DiffHunk> {
+        if hunks.is_empty() {
+            return None;
+        }
+        match direction {
+            Direction::Next => hunks
+                .iter()
+                .find(|hunk| hunk.row_range.start > row)
+                .or_else(|| hunks.first()),
+            Direction::Prev => hunks
+                .iter()
+                .rev()
+                .find(|hunk| hunk.row_range.end < row)
+                .or_else(|| hunks.last()),
+        }
+    }
+
+    /// Copy the hunk under the cursor from the left (old) buffer into the
+    /// right (new) buffer, discarding whatever change that hunk made.
+    pub fn apply_hunk_from_left(
+        &mut self,
+        _: &ApplyHunkFromLeft,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.apply_hunk(HunkApplyDirection::LeftToRight, window, cx);
+    }
+
+    /// Copy the hunk under the cursor from the right (new) buffer back into
+    /// the left (old) buffer. A no-op in three-way mode, where the left
+    /// buffer is the merge ancestor shared with the theirs-diff and can't be
+    /// rewritten without corrupting that comparison.
+    pub fn revert_hunk(&mut self, _: &RevertHunk, window: &mut Window, cx: &mut Context<Self>) {
+        self.apply_hunk(HunkApplyDirection::RightToLeft, window, cx);
+    }
+
+    fn apply_hunk(
+        &mut self,
+        direction: HunkApplyDirection,
+        _window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        // Reverting writes into old_buffer, which in three-way mode is the
+        // shared merge ancestor that theirs_diff is also computed against;
+        // mutating it there would corrupt the theirs-pane comparison and has
+        // no save/reload path (can_save/save only cover right_editor).
+        if direction == HunkApplyDirection::RightToLeft && self.theirs.is_some() {
+            return;
+        }
+
+        let Some(hunk) = self.hunk_under_cursor(cx) else {
+            return;
+        };
+
+        let old_snapshot = self.old_buffer.read(cx).snapshot();
+        let new_snapshot = self.new_buffer.read(cx).snapshot();
+        let old_range = hunk.diff_base_byte_range.clone();
+        let new_start = new_snapshot.point_to_offset(Point::new(hunk.row_range.start, 0));
+        let new_end = new_snapshot.point_to_offset(Point::new(hunk.row_range.end, 0));
+
+        match direction {
+            HunkApplyDirection::LeftToRight => {
+                let old_text: String = old_snapshot.text_for_range(old_range).collect();
+                self.new_buffer.update(cx, |buffer, cx| {
+                    buffer.edit([(new_start..new_end, old_text)], None, cx);
+                });
+            }
+            HunkApplyDirection::RightToLeft => {
+                let new_text: String = new_snapshot.text_for_range(new_start..new_end).collect();
+                self.old_buffer.update(cx, |buffer, cx| {
+                    buffer.edit([(old_range, new_text)], None, cx);
+                });
+            }
+        }
+    }
+
+    /// Find the hunk that contains the cursor of whichever pane currently
+    /// has focus, in that pane's own row space.
+    fn hunk_under_cursor(&self, cx: &mut Context<Self>) -> Option<buffer_diff::DiffHunk> {
+        let new_snapshot = self.new_buffer.read(cx).snapshot();
+        let old_snapshot = self.old_buffer.read(cx).snapshot();
+        let diff_snapshot = self.diff.read(cx).snapshot(cx);
+        let hunks = diff_snapshot.hunks_in_row_range(0..u32::MAX, &new_snapshot);
+
+        match self.focused_pane {
+            FocusedPane::Left => {
+                let row = self
+                    .left_editor
+                    .read(cx)
+                    .selections
+                    .newest::<Point>(cx)
+                    .head()
+                    .row;
+                hunks.into_iter().find(|hunk| {
+                    let old_start = old_snapshot
+                        .offset_to_point(hunk.diff_base_byte_range.start)
+                        .row;
+                    let old_end = old_snapshot
+                        .offset_to_point(hunk.diff_base_byte_range.end)
+                        .row;
+                    row >= old_start && row < old_end.max(old_start + 1)
+                })
+            }
+            FocusedPane::Right => {
+                let row = self
+                    .right_editor
+                    .read(cx)
+                    .selections
+                    .newest::<Point>(cx)
+                    .head()
+                    .row;
+                hunks.into_iter().find(|hunk| {
+                    row >= hunk.row_range.start
+                        && row < hunk.row_range.end.max(hunk.row_range.start + 1)
+                })
+            }
+            // Apply/revert only ever act on the base↔ours pair; the theirs
+            // pane is reference-only for now.
+            FocusedPane::Theirs => None,
         }
     }
 }
 
+/// Background tint for an intra-line word diff span: red-ish for deletions
+/// on the old side, green-ish for insertions on the new side.
+fn intra_hunk_highlight_style(is_insertion: bool, cx: &App) -> gpui::HighlightStyle {
+    let color = if is_insertion {
+        Color::Created
+    } else {
+        Color::Deleted
+    };
+    gpui::HighlightStyle {
+        background_color: Some(color.color(cx).alpha(0.4)),
+        ..Default::default()
+    }
+}
+
 async fn build_buffer_diff(
     old_buffer: &Entity<Buffer>,
     new_buffer: &Entity<Buffer>,
@@ -250,6 +1097,11 @@ impl Focusable for SideBySideDiffView {
         match self.focused_pane {
             FocusedPane::Left => self.left_editor.focus_handle(cx),
             FocusedPane::Right => self.right_editor.focus_handle(cx),
+            FocusedPane::Theirs => self
+                .theirs
+                .as_ref()
+                .map(|theirs| theirs.editor.focus_handle(cx))
+                .unwrap_or_else(|| self.right_editor.focus_handle(cx)),
         }
     }
 }
@@ -319,6 +1171,11 @@ impl Item for SideBySideDiffView {
             .update(cx, |editor, cx| editor.deactivated(window, cx));
         self.right_editor
             .update(cx, |editor, cx| editor.deactivated(window, cx));
+        if let Some(theirs) = &self.theirs {
+            theirs
+                .editor
+                .update(cx, |editor, cx| editor.deactivated(window, cx));
+        }
     }
 
     fn is_singleton(&self, _: &App) -> bool {
@@ -338,6 +1195,11 @@ impl Item for SideBySideDiffView {
             match self.focused_pane {
                 FocusedPane::Left => Some(self.left_editor.to_any()),
                 FocusedPane::Right => Some(self.right_editor.to_any()),
+                FocusedPane::Theirs => self
+                    .theirs
+                    .as_ref()
+                    .map(|theirs| theirs.editor.to_any())
+                    .or_else(|| Some(self.right_editor.to_any())),
             }
         } else {
             None
@@ -349,6 +1211,11 @@ impl Item for SideBySideDiffView {
         match self.focused_pane {
             FocusedPane::Left => Some(Box::new(self.left_editor.clone())),
             FocusedPane::Right => Some(Box::new(self.right_editor.clone())),
+            FocusedPane::Theirs => self
+                .theirs
+                .as_ref()
+                .map(|theirs| Box::new(theirs.editor.clone()) as Box<dyn SearchableItemHandle>)
+                .or_else(|| Some(Box::new(self.right_editor.clone()))),
         }
     }
 
@@ -359,6 +1226,9 @@ impl Item for SideBySideDiffView {
     ) {
         self.left_editor.for_each_project_item(cx, f);
         self.right_editor.for_each_project_item(cx, f);
+        if let Some(theirs) = &self.theirs {
+            theirs.editor.for_each_project_item(cx, f);
+        }
     }
 
     fn set_nav_history(
@@ -395,6 +1265,10 @@ impl Item for SideBySideDiffView {
         match self.focused_pane {
             FocusedPane::Left => self.left_editor.breadcrumbs(theme, cx),
             FocusedPane::Right => self.right_editor.breadcrumbs(theme, cx),
+            FocusedPane::Theirs => self
+                .theirs
+                .as_ref()
+                .and_then(|theirs| theirs.editor.breadcrumbs(theme, cx)),
         }
     }
 
@@ -410,10 +1284,16 @@ impl Item for SideBySideDiffView {
         self.right_editor.update(cx, |editor, cx| {
             editor.added_to_workspace(workspace, window, cx)
         });
+        if let Some(theirs) = &self.theirs {
+            theirs.editor.update(cx, |editor, cx| {
+                editor.added_to_workspace(workspace, window, cx)
+            });
+        }
     }
 
     fn can_save(&self, cx: &App) -> bool {
-        // The right editor handles the new buffer, so delegate to it
+        // The right editor always manages the editable result buffer ("ours"
+        // in three-way mode), so delegate to it regardless of layout.
         self.right_editor.read(cx).can_save(cx)
     }
 
@@ -424,25 +1304,40 @@ impl Item for SideBySideDiffView {
         window: &mut Window,
         cx: &mut Context<Self>,
     ) -> Task<Result<()>> {
-        // Delegate saving to the right editor, which manages the new buffer
         self.right_editor
             .update(cx, |editor, cx| editor.save(options, project, window, cx))
     }
 }
 
 impl Render for SideBySideDiffView {
-    fn render(&mut self, _: &mut Window, _: &mut Context<Self>) -> impl IntoElement {
-        // Create a horizontal split view with the two editors
-        ui::div()
+    fn render(&mut self, _: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let container = ui::div()
             .flex()
             .h_full()
+            .on_action(cx.listener(Self::go_to_next_hunk))
+            .on_action(cx.listener(Self::go_to_prev_hunk))
+            .on_action(cx.listener(Self::apply_hunk_from_left))
+            .on_action(cx.listener(Self::revert_hunk))
             .child(
                 ui::div()
                     .flex_1()
                     .border_r_1()
                     .child(self.left_editor.clone()),
-            )
-            .child(ui::div().flex_1().child(self.right_editor.clone()))
+            );
+
+        // Three-way merge mode adds a middle "theirs" column between base
+        // and ours; otherwise this is the regular two-pane diff view.
+        match &self.theirs {
+            Some(theirs) => container
+                .child(
+                    ui::div()
+                        .flex_1()
+                        .border_r_1()
+                        .child(theirs.editor.clone()),
+                )
+                .child(ui::div().flex_1().child(self.right_editor.clone())),
+            None => container.child(ui::div().flex_1().child(self.right_editor.clone())),
+        }
     }
 }
 
@@ -468,4 +1363,95 @@ mod tests {
     }
 
     // TODO: Add proper tests once the basic functionality is working
+
+    fn row_map(segments: &[(f32, f32, f32, f32)]) -> RowMap {
+        RowMap {
+            segments: segments
+                .iter()
+                .map(|&(old_start, old_end, new_start, new_end)| RowMapSegment {
+                    old_start,
+                    old_end,
+                    new_start,
+                    new_end,
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_row_map_identity_for_unchanged_segment() {
+        let map = row_map(&[(0., 10., 0., 10.)]);
+        assert_eq!(map.old_to_new(5.), 5.);
+        assert_eq!(map.new_to_old(5.), 5.);
+    }
+
+    #[test]
+    fn test_row_map_interpolates_across_resized_hunk() {
+        // Old buffer had a 2-row hunk that became 4 rows in the new buffer.
+        let map = row_map(&[(0., 2., 0., 4.)]);
+        assert_eq!(map.old_to_new(0.), 0.);
+        assert_eq!(map.old_to_new(1.), 2.);
+        assert_eq!(map.old_to_new(2.), 4.);
+        assert_eq!(map.new_to_old(4.), 2.);
+    }
+
+    #[test]
+    fn test_row_map_picks_segment_containing_row() {
+        let map = row_map(&[(0., 10., 0., 10.), (10., 12., 10., 16.)]);
+        assert_eq!(map.old_to_new(11.), 13.);
+    }
+
+    #[test]
+    fn test_row_map_extrapolates_past_last_segment() {
+        let map = row_map(&[(0., 10., 0., 16.)]);
+        // Ten rows past the end of the only segment keeps the same offset.
+        assert_eq!(map.old_to_new(20.), 26.);
+    }
+
+    #[test]
+    fn test_row_map_empty_is_identity() {
+        let map = row_map(&[]);
+        assert_eq!(map.old_to_new(7.), 7.);
+        assert_eq!(map.new_to_old(7.), 7.);
+    }
+
+    #[test]
+    fn test_tokenize_line() {
+        let line = "let foo_bar = 1;";
+        let tokens: Vec<&str> = tokenize_line(line).into_iter().map(|r| &line[r]).collect();
+        assert_eq!(tokens, ["let", " ", "foo_bar", " = ", "1", ";"]);
+    }
+
+    #[test]
+    fn test_tokenize_line_empty() {
+        assert_eq!(tokenize_line(""), Vec::<Range<usize>>::new());
+    }
+
+    #[test]
+    fn test_diff_tokens_replaces_changed_word() {
+        let old = ["let", " ", "foo", " = ", "1", ";"];
+        let new = ["let", " ", "bar", " = ", "1", ";"];
+        let ops = diff_tokens(&old, &new);
+        assert_eq!(
+            ops,
+            [(TokenDiffOp::Delete, 2), (TokenDiffOp::Insert, 2)]
+        );
+    }
+
+    #[test]
+    fn test_diff_tokens_identical_lines_have_no_ops() {
+        let tokens = ["let", " ", "foo", " = ", "1", ";"];
+        assert_eq!(diff_tokens(&tokens, &tokens), []);
+    }
+
+    #[test]
+    fn test_diff_tokens_trailing_insert() {
+        let old = ["foo"];
+        let new = ["foo", " ", "bar"];
+        let ops = diff_tokens(&old, &new);
+        assert_eq!(
+            ops,
+            [(TokenDiffOp::Insert, 1), (TokenDiffOp::Insert, 2)]
+        );
+    }
 }